@@ -0,0 +1,167 @@
+use crate::benchmark::benchmark_algorithm;
+use crate::blocked_matmul::blocked_matmul;
+use crate::divide_conquer_matmul::divide_conquer_matmul;
+use crate::matrix::Matrix;
+use crate::strassen_matmul::strassen_matmul;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Candidate recursion thresholds tried during calibration for
+/// `divide_conquer_matmul` and `strassen_matmul`.
+const CANDIDATE_THRESHOLDS: [usize; 4] = [32, 64, 128, 256];
+
+/// Candidate `(block_i, block_j, block_k)` triples tried during calibration
+/// for `blocked_matmul`.
+const CANDIDATE_BLOCKS: [(usize, usize, usize); 4] = [
+	(32, 32, 128),
+	(64, 64, 256),
+	(128, 128, 256),
+	(64, 32, 512),
+];
+
+/// Matrix size used for calibration runs: large enough that recursion and
+/// blocking actually kick in, small enough that sweeping every candidate
+/// stays fast.
+const CALIBRATION_SIZE: usize = 512;
+
+/// Recursion threshold and block sizes chosen by `calibrate`, threaded down
+/// through the benchmark runs in place of the previously hardcoded
+/// constants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TunedParams {
+	pub divide_conquer_threshold: usize,
+	pub strassen_threshold: usize,
+	pub block_i: usize,
+	pub block_j: usize,
+	pub block_k: usize,
+}
+
+impl TunedParams {
+	/// Parse from the tiny hand-rolled JSON object written by `to_json`.
+	fn parse(contents: &str) -> Option<Self> {
+		let mut fields = std::collections::HashMap::new();
+		let body = contents.trim().trim_start_matches('{').trim_end_matches('}');
+		for entry in body.split(',') {
+			let mut parts = entry.splitn(2, ':');
+			let key = parts.next()?.trim().trim_matches('"');
+			let value: usize = parts.next()?.trim().parse().ok()?;
+			fields.insert(key, value);
+		}
+
+		Some(TunedParams {
+			divide_conquer_threshold: *fields.get("divide_conquer_threshold")?,
+			strassen_threshold: *fields.get("strassen_threshold")?,
+			block_i: *fields.get("block_i")?,
+			block_j: *fields.get("block_j")?,
+			block_k: *fields.get("block_k")?,
+		})
+	}
+
+	/// Serialize to the same tiny JSON object `parse` reads back.
+	fn to_json(self) -> String {
+		format!(
+			"{{\"divide_conquer_threshold\":{},\"strassen_threshold\":{},\"block_i\":{},\"block_j\":{},\"block_k\":{}}}",
+			self.divide_conquer_threshold, self.strassen_threshold, self.block_i, self.block_j, self.block_k
+		)
+	}
+}
+
+/// Path to the calibration cache for the current OS/arch/thread count,
+/// mirroring the `{os}_{arch}_{threads}t` naming scheme used for CSV result
+/// files in `main`.
+fn cache_path(num_threads: usize) -> PathBuf {
+	let os = std::env::consts::OS;
+	let arch = std::env::consts::ARCH;
+	Path::new("../results/data").join(format!("autotune_{os}_{arch}_{num_threads}t.json"))
+}
+
+/// Load cached tuned parameters for this machine if a calibration pass was
+/// already run and cached, otherwise run `calibrate` and cache the result.
+pub fn get_or_calibrate(num_threads: usize) -> TunedParams {
+	let path = cache_path(num_threads);
+
+	if let Ok(contents) = fs::read_to_string(&path) {
+		if let Some(params) = TunedParams::parse(&contents) {
+			println!("Loaded cached autotune parameters from: {}", path.display());
+			return params;
+		}
+		eprintln!("Ignoring unreadable autotune cache at: {}", path.display());
+	}
+
+	println!("\nCalibrating recursion threshold and block sizes...");
+	let params = calibrate();
+
+	if let Some(parent) = path.parent() {
+		let _ = fs::create_dir_all(parent);
+	}
+	match fs::write(&path, params.to_json()) {
+		Ok(()) => println!("Cached autotune parameters to: {}", path.display()),
+		Err(e) => eprintln!("Error caching autotune parameters: {}", e),
+	}
+
+	params
+}
+
+/// Time `time_fn` for every candidate and return whichever candidate came
+/// back fastest.
+fn fastest<T: Copy>(candidates: &[T], mut time_fn: impl FnMut(T) -> f64) -> T {
+	candidates
+		.iter()
+		.copied()
+		.map(|candidate| (candidate, time_fn(candidate)))
+		.min_by(|(_, t1), (_, t2)| t1.partial_cmp(t2).unwrap())
+		.unwrap()
+		.0
+}
+
+/// Sweep `CANDIDATE_THRESHOLDS` and `CANDIDATE_BLOCKS` on a
+/// `CALIBRATION_SIZE` matrix pair, timing each with the same warmup plus
+/// 10-sample methodology as the main benchmarks, and keep the fastest
+/// configuration per algorithm.
+fn calibrate() -> TunedParams {
+	let a = Matrix::random(CALIBRATION_SIZE, CALIBRATION_SIZE);
+	let b = Matrix::random(CALIBRATION_SIZE, CALIBRATION_SIZE);
+
+	let divide_conquer_threshold = fastest(&CANDIDATE_THRESHOLDS, |threshold| {
+		let (time, _) = benchmark_algorithm(
+			|a, b| divide_conquer_matmul(a, b, threshold, true),
+			"DivideConquer-calibrate",
+			&a,
+			&b,
+		);
+		time
+	});
+
+	let strassen_threshold = fastest(&CANDIDATE_THRESHOLDS, |threshold| {
+		let (time, _) = benchmark_algorithm(
+			|a, b| strassen_matmul(a, b, threshold, true),
+			"Strassen-calibrate",
+			&a,
+			&b,
+		);
+		time
+	});
+
+	let (block_i, block_j, block_k) = fastest(&CANDIDATE_BLOCKS, |(block_i, block_j, block_k)| {
+		let (time, _) = benchmark_algorithm(
+			|a, b| blocked_matmul(a, b, block_i, block_j, block_k),
+			"Blocked-calibrate",
+			&a,
+			&b,
+		);
+		time
+	});
+
+	println!(
+		"  Chosen: divide_conquer_threshold={}, strassen_threshold={}, block=({}, {}, {})",
+		divide_conquer_threshold, strassen_threshold, block_i, block_j, block_k
+	);
+
+	TunedParams {
+		divide_conquer_threshold,
+		strassen_threshold,
+		block_i,
+		block_j,
+		block_k,
+	}
+}
@@ -1,9 +1,13 @@
+use crate::autotune::TunedParams;
 use crate::iterative_matmul::iterative_matmul;
 use crate::divide_conquer_matmul::divide_conquer_matmul;
 use crate::strassen_matmul::strassen_matmul;
+use crate::blocked_matmul::blocked_matmul;
 use crate::matrix::Matrix;
+use crate::sparse_matrix::{sparse_dense_matmul, SparseMatrix};
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use std::time::Instant;
 use stats_alloc::{Region, INSTRUMENTED_SYSTEM};
 
@@ -14,6 +18,16 @@ pub struct BenchmarkResult {
 	pub algorithm: String,
 	pub time: f64,   // in milliseconds
 	pub memory: f64, // in megabytes
+	/// Nonzero density of the input, for sparse-vs-dense comparisons.
+	/// `None` for the regular dense algorithm benchmarks.
+	pub density: Option<f64>,
+	/// Storage backing of the input matrices: `"heap"` for the usual
+	/// `Vec`-backed `Matrix`, `"mmap"` for memory-mapped, out-of-core ones.
+	/// `stats_alloc` only tracks the global (heap) allocator, so the mapped
+	/// input storage never shows up in `memory` for `"mmap"` rows; `memory`
+	/// there counts only the heap allocations the algorithm itself makes
+	/// (such as its output matrix).
+	pub backing: String,
 }
 
 /// Calculate the mean of a vector of f64 values.
@@ -23,7 +37,7 @@ fn calculate_mean(values: &[f64]) -> f64 {
 
 /// Benchmark a single algorithm with given matrices.
 /// Returns the mean time in milliseconds and mean memory allocated in megabytes.
-fn benchmark_algorithm<F>(algorithm_fn: F, name: &str, a: &Matrix, b: &Matrix) -> (f64, f64)
+pub(crate) fn benchmark_algorithm<F>(algorithm_fn: F, name: &str, a: &Matrix, b: &Matrix) -> (f64, f64)
 where
 	F: Fn(&Matrix, &Matrix) -> Matrix,
 {
@@ -54,9 +68,110 @@ where
 	(mean_time, mean_memory)
 }
 
+/// Run the iterative, divide-conquer, Strassen and blocked algorithms on a
+/// single `(a, b)` pair, appending their results (labeled with `size`) to
+/// `results`. `params` supplies the recursion threshold and block sizes
+/// chosen by `autotune::get_or_calibrate`, in place of hardcoded constants.
+fn benchmark_pair(size: usize, a: &Matrix, b: &Matrix, params: &TunedParams, results: &mut Vec<BenchmarkResult>) {
+	// Benchmark iterative algorithm
+	match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		let (time_iterative, memory_iterative) = benchmark_algorithm(|a, b| iterative_matmul(a, b), "Iterative", a, b);
+		results.push(BenchmarkResult {
+			size,
+			algorithm: "Iterative".to_string(),
+			time: time_iterative,
+			memory: memory_iterative,
+			density: None,
+			backing: "heap".to_string(),
+		});
+		println!(
+			"    Time: {:.2} ms, Memory: {:.2} MB",
+			time_iterative, memory_iterative
+		);
+	})) {
+		Err(e) => println!("    Error: {:?}", e),
+		Ok(_) => {}
+	}
+
+	// Benchmark divide-and-conquer (always parallel)
+	match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		let (time_dc, memory_dc) = benchmark_algorithm(
+			|a, b| divide_conquer_matmul(a, b, params.divide_conquer_threshold, true),
+			"Divide-Conquer",
+			a,
+			b,
+		);
+		results.push(BenchmarkResult {
+			size,
+			algorithm: "Divide-Conquer".to_string(),
+			time: time_dc,
+			memory: memory_dc,
+			density: None,
+			backing: "heap".to_string(),
+		});
+		println!(
+			"    Time: {:.2} ms, Memory: {:.2} MB",
+			time_dc, memory_dc
+		);
+	})) {
+		Err(e) => println!("    Error: {:?}", e),
+		Ok(_) => {}
+	}
+
+	// Benchmark Strassen (always parallel)
+	match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		let (time_strassen, memory_strassen) = benchmark_algorithm(
+			|a, b| strassen_matmul(a, b, params.strassen_threshold, true),
+			"Strassen",
+			a,
+			b,
+		);
+		results.push(BenchmarkResult {
+			size,
+			algorithm: "Strassen".to_string(),
+			time: time_strassen,
+			memory: memory_strassen,
+			density: None,
+			backing: "heap".to_string(),
+		});
+		println!(
+			"    Time: {:.2} ms, Memory: {:.2} MB",
+			time_strassen, memory_strassen
+		);
+	})) {
+		Err(e) => println!("    Error: {:?}", e),
+		Ok(_) => {}
+	}
+
+	// Benchmark blocked (tiled) algorithm
+	match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		let (time_blocked, memory_blocked) = benchmark_algorithm(
+			|a, b| blocked_matmul(a, b, params.block_i, params.block_j, params.block_k),
+			"Blocked",
+			a,
+			b,
+		);
+		results.push(BenchmarkResult {
+			size,
+			algorithm: "Blocked".to_string(),
+			time: time_blocked,
+			memory: memory_blocked,
+			density: None,
+			backing: "heap".to_string(),
+		});
+		println!(
+			"    Time: {:.2} ms, Memory: {:.2} MB",
+			time_blocked, memory_blocked
+		);
+	})) {
+		Err(e) => println!("    Error: {:?}", e),
+		Ok(_) => {}
+	}
+}
+
 /// Run benchmarks for different matrix sizes and algorithms.
 /// Returns a vector of BenchmarkResult objects.
-pub fn run_benchmarks(sizes: &Vec<usize>) -> Vec<BenchmarkResult> {
+pub fn run_benchmarks(sizes: &Vec<usize>, params: &TunedParams) -> Vec<BenchmarkResult> {
 	let mut results = Vec::new();
 
 	for &n in sizes {
@@ -67,68 +182,204 @@ pub fn run_benchmarks(sizes: &Vec<usize>) -> Vec<BenchmarkResult> {
 		let a = Matrix::random(n, n);
 		let b = Matrix::random(n, n);
 
-		// Benchmark iterative algorithm
-		match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-			let (time_iterative, memory_iterative) = benchmark_algorithm(|a, b| iterative_matmul(a, b), "Iterative", &a, &b);
-			results.push(BenchmarkResult {
-				size: n,
-				algorithm: "Iterative".to_string(),
-				time: time_iterative,
-				memory: memory_iterative,
-			});
+		benchmark_pair(n, &a, &b, params, &mut results);
+	}
+
+	results
+}
+
+/// Benchmark the sparse CSC kernel against a dense matrix `b`, mirroring
+/// `benchmark_algorithm`'s warmup + 10-sample methodology.
+fn benchmark_sparse_algorithm(a: &SparseMatrix, b: &Matrix) -> (f64, f64) {
+	println!("  Benchmarking Sparse...");
+
+	// Warmup run
+	let _ = sparse_dense_matmul(a, b);
+
+	let samples = 10;
+	let mut times = Vec::with_capacity(samples);
+	let mut memory_allocations = Vec::with_capacity(samples);
+
+	for _ in 0..samples {
+		let reg: Region<'_, std::alloc::System> = Region::new(&INSTRUMENTED_SYSTEM);
+		let start = Instant::now();
+		let _ = sparse_dense_matmul(a, b);
+		let duration = start.elapsed();
+		let stats = reg.change();
+
+		times.push(duration.as_secs_f64() * 1000.0);
+		memory_allocations.push(stats.bytes_allocated as f64 / 1e6);
+	}
+
+	(calculate_mean(&times), calculate_mean(&memory_allocations))
+}
+
+/// Sanity-check that the sparse CSC kernel agrees with the dense iterative
+/// reference on `a_sparse.to_dense()`, so a conversion or kernel bug shows
+/// up immediately rather than silently skewing the benchmark numbers.
+fn verify_sparse_matmul(a_sparse: &SparseMatrix, b: &Matrix) {
+	let expected = iterative_matmul(&a_sparse.to_dense(), b);
+	let actual = sparse_dense_matmul(a_sparse, b);
+
+	let max_diff = expected
+		.data
+		.iter()
+		.zip(actual.data.iter())
+		.map(|(e, a)| (e - a).abs())
+		.fold(0.0_f64, f64::max);
+
+	if max_diff > 1e-6 {
+		eprintln!("  Warning: sparse/dense mismatch, max diff = {:.2e}", max_diff);
+	}
+}
+
+/// Run benchmarks comparing dense iterative multiplication against the
+/// sparse CSC kernel across several nonzero densities, for each given
+/// matrix size. Reports the crossover density at which sparse stops
+/// winning on both time and memory.
+pub fn run_sparse_benchmarks(sizes: &[usize], densities: &[f64]) -> Vec<BenchmarkResult> {
+	let mut results = Vec::new();
+
+	for &n in sizes {
+		println!("\nTesting sparse vs. dense at size: {}x{}", n, n);
+		println!("{}", "=".repeat(50));
+
+		let mut crossover_density = None;
+
+		for &density in densities {
+			let a_dense = Matrix::random_sparse(n, n, density);
+			let a_sparse = SparseMatrix::from(&a_dense);
+			let b = Matrix::random(n, n);
+
 			println!(
-				"    Time: {:.2} ms, Memory: {:.2} MB",
-				time_iterative, memory_iterative
+				"\n  Density: {:.1}% (nnz = {})",
+				density * 100.0,
+				a_sparse.nnz()
 			);
-		})) {
-			Err(e) => println!("    Error: {:?}", e),
-			Ok(_) => {}
-		}
+			verify_sparse_matmul(&a_sparse, &b);
 
-		// Benchmark divide-and-conquer (always parallel)
-		match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-			let (time_dc, memory_dc) = benchmark_algorithm(
-				|a, b| divide_conquer_matmul(a, b, 64, true),
-				"Divide-Conquer",
-				&a,
-				&b,
-			);
+			let (time_dense, memory_dense) = benchmark_algorithm(|a, b| iterative_matmul(a, b), "Dense", &a_dense, &b);
 			results.push(BenchmarkResult {
 				size: n,
-				algorithm: "Divide-Conquer".to_string(),
-				time: time_dc,
-				memory: memory_dc,
+				algorithm: "Dense".to_string(),
+				time: time_dense,
+				memory: memory_dense,
+				density: Some(density),
+				backing: "heap".to_string(),
 			});
-			println!(
-				"    Time: {:.2} ms, Memory: {:.2} MB",
-				time_dc, memory_dc
-			);
-		})) {
-			Err(e) => println!("    Error: {:?}", e),
-			Ok(_) => {}
-		}
+			println!("    Time: {:.2} ms, Memory: {:.2} MB", time_dense, memory_dense);
 
-		// Benchmark Strassen (always parallel)
-		match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-			let (time_strassen, memory_strassen) = benchmark_algorithm(
-				|a, b| strassen_matmul(a, b, 64, true),
-				"Strassen",
-				&a,
-				&b,
-			);
+			let (time_sparse, memory_sparse) = benchmark_sparse_algorithm(&a_sparse, &b);
 			results.push(BenchmarkResult {
 				size: n,
-				algorithm: "Strassen".to_string(),
-				time: time_strassen,
-				memory: memory_strassen,
+				algorithm: "Sparse".to_string(),
+				time: time_sparse,
+				memory: memory_sparse,
+				density: Some(density),
+				backing: "heap".to_string(),
 			});
-			println!(
-				"    Time: {:.2} ms, Memory: {:.2} MB",
-				time_strassen, memory_strassen
-			);
-		})) {
-			Err(e) => println!("    Error: {:?}", e),
-			Ok(_) => {}
+			println!("    Time: {:.2} ms, Memory: {:.2} MB", time_sparse, memory_sparse);
+
+			if crossover_density.is_none() && (time_sparse >= time_dense || memory_sparse >= memory_dense) {
+				crossover_density = Some(density);
+			}
+		}
+
+		match crossover_density {
+			Some(d) => println!(
+				"\n  Crossover density (sparse stops winning on time and memory): {:.1}%",
+				d * 100.0
+			),
+			None => println!("\n  Sparse wins on time and memory across all tested densities"),
+		}
+	}
+
+	results
+}
+
+/// Run benchmarks against matrices loaded from disk (e.g. MatrixMarket
+/// files) instead of randomly generated ones. Each matrix `a` is paired
+/// with a randomly generated square matrix `b` of compatible dimensions
+/// (`b.rows == b.cols == a.cols`), so non-square `a` is benchmarked as-is.
+pub fn run_benchmarks_on_matrices(matrices: &[(String, Matrix)], params: &TunedParams) -> Vec<BenchmarkResult> {
+	let mut results = Vec::new();
+
+	for (label, a) in matrices {
+		println!("\nTesting matrix: {} ({}x{})", label, a.rows, a.cols);
+		println!("{}", "=".repeat(50));
+
+		let b = Matrix::random(a.cols, a.cols);
+
+		benchmark_pair(a.rows, a, &b, params, &mut results);
+	}
+
+	results
+}
+
+/// Run the iterative and blocked algorithms on matrices backed by
+/// memory-mapped files under `dir` rather than the heap, so sizes beyond
+/// available RAM (e.g. 8192 and up) can be benchmarked out-of-core.
+/// Matrices that fail to map (e.g. insufficient disk space) are skipped.
+pub fn run_out_of_core_benchmarks(sizes: &[usize], dir: &Path, params: &TunedParams) -> Vec<BenchmarkResult> {
+	let mut results = Vec::new();
+
+	for &n in sizes {
+		println!("\nTesting out-of-core matrix size: {}x{}", n, n);
+		println!("{}", "=".repeat(50));
+
+		let mut a = match Matrix::mmap(n, n, dir.join(format!("a_{n}.bin"))) {
+			Ok(m) => m,
+			Err(e) => {
+				eprintln!("  Skipping size {}: failed to map A: {}", n, e);
+				continue;
+			}
+		};
+		let mut b = match Matrix::mmap(n, n, dir.join(format!("b_{n}.bin"))) {
+			Ok(m) => m,
+			Err(e) => {
+				eprintln!("  Skipping size {}: failed to map B: {}", n, e);
+				continue;
+			}
+		};
+		a.fill_random();
+		b.fill_random();
+
+		// Spot-check that the fill actually landed, via a zero-copy view of
+		// just the first column rather than copying the (potentially huge)
+		// matrix just to eyeball it.
+		for (label, matrix) in [("A", &a), ("B", &b)] {
+			let first_column = matrix
+				.submatrix_view(0, matrix.rows, 0, 1)
+				.expect("full-row-range column view is always available");
+			if (0..first_column.rows()).all(|i| first_column[(i, 0)] == 0.0) {
+				eprintln!("  Warning: out-of-core matrix {} looks unfilled (first column is all zero)", label);
+			}
+		}
+
+		let algorithms: Vec<(&str, Box<dyn Fn(&Matrix, &Matrix) -> Matrix>)> = vec![
+			("Iterative", Box::new(iterative_matmul)),
+			(
+				"Blocked",
+				Box::new(|a, b| blocked_matmul(a, b, params.block_i, params.block_j, params.block_k)),
+			),
+		];
+		for (name, algorithm_fn) in algorithms {
+			match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+				benchmark_algorithm(algorithm_fn, name, &a, &b)
+			})) {
+				Ok((time, memory)) => {
+					results.push(BenchmarkResult {
+						size: n,
+						algorithm: name.to_string(),
+						time,
+						memory,
+						density: None,
+						backing: "mmap".to_string(),
+					});
+					println!("    Time: {:.2} ms, Memory (heap): {:.2} MB", time, memory);
+				}
+				Err(e) => println!("    Error: {:?}", e),
+			}
 		}
 	}
 
@@ -141,15 +392,19 @@ pub fn print_results_table(results: &Vec<BenchmarkResult>) {
 	println!("BENCHMARK RESULTS SUMMARY");
 	println!("{}", "=".repeat(80));
 	println!(
-		"{:<10} {:<25} {:>12} {:>15}",
-		"Size", "Algorithm", "Time (ms)", "Memory (MB)"
+		"{:<10} {:<25} {:>12} {:>15} {:>10} {:>8}",
+		"Size", "Algorithm", "Time (ms)", "Memory (MB)", "Density", "Backing"
 	);
 	println!("{}", "-".repeat(80));
 
 	for result in results {
+		let density = result
+			.density
+			.map(|d| format!("{:.1}%", d * 100.0))
+			.unwrap_or_default();
 		println!(
-			"{:<10} {:<25} {:>12.2} {:>15.2}",
-			result.size, result.algorithm, result.time, result.memory
+			"{:<10} {:<25} {:>12.2} {:>15.2} {:>10} {:>8}",
+			result.size, result.algorithm, result.time, result.memory, density, result.backing
 		);
 	}
 
@@ -159,13 +414,17 @@ pub fn print_results_table(results: &Vec<BenchmarkResult>) {
 /// Save benchmark results to a CSV file
 pub fn save_results_csv(results: &Vec<BenchmarkResult>, filename: &str) -> std::io::Result<()> {
 	let mut file = File::create(filename)?;
-	writeln!(file, "size,algorithm,time_ms,memory_mb")?;
+	writeln!(file, "size,algorithm,time_ms,memory_mb,density,backing")?;
 
 	for result in results {
+		let density = result
+			.density
+			.map(|d| d.to_string())
+			.unwrap_or_default();
 		writeln!(
 			file,
-			"{},{},{},{}",
-			result.size, result.algorithm, result.time, result.memory
+			"{},{},{},{},{},{}",
+			result.size, result.algorithm, result.time, result.memory, density, result.backing
 		)?;
 	}
 
@@ -1,4 +1,3 @@
-use crate::classic_matmul::classic_matmul;
 use crate::matrix::Matrix;
 use rayon::prelude::*;
 
@@ -27,18 +26,28 @@ pub fn divide_conquer_matmul(a: &Matrix, b: &Matrix, threshold: usize, parallel:
 		);
 	}
 
-	divide_conquer_recursive(a, b, threshold, parallel)
+	let mut c = Matrix::new(m, p);
+	divide_conquer_recursive(a, b, threshold, parallel, &mut c, 0.0);
+	c
 }
 
 /// Internal recursive function for divide and conquer multiplication.
-fn divide_conquer_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel: bool) -> Matrix {
+/// Accumulates `A*B` into `c` as `c = beta*c + A*B`, writing directly into
+/// the preallocated output buffer rather than allocating and summing
+/// separate product matrices. Note this only cuts allocations on the output
+/// side: the `A`/`B` quadrants below still go through the copying
+/// `submatrix`, since a quadrant split on both rows and columns isn't
+/// contiguous in column-major storage and so can't be borrowed as a
+/// zero-copy view the way `Matrix::submatrix_view`'s column ranges can.
+fn divide_conquer_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel: bool, c: &mut Matrix, beta: f64) {
 	let m = a.rows;
 	let n = a.cols;
 	let p = b.cols;
 
 	// Base case: use classic implementation for fair comparison
 	if m <= threshold || n <= threshold || p <= threshold {
-		return classic_matmul(a, b);
+		Matrix::gemm(a, b, c, 1.0, beta);
+		return;
 	}
 
 	// Divide matrices into quadrants
@@ -58,55 +67,63 @@ fn divide_conquer_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel:
 	let b21 = b.submatrix(n_half, n, 0, p_half);
 	let b22 = b.submatrix(n_half, n, p_half, p);
 
-	// Compute the 8 products needed (C = A * B)
 	// C11 = A11*B11 + A12*B21
 	// C12 = A11*B12 + A12*B22
 	// C21 = A21*B11 + A22*B21
 	// C22 = A21*B12 + A22*B22
-
-	let (c11, c12, c21, c22) = if parallel {
-		// Parallel computation using rayon
-		let results: Vec<Matrix> = vec![
-			(&a11, &b11),
-			(&a12, &b21),
-			(&a11, &b12),
-			(&a12, &b22),
-			(&a21, &b11),
-			(&a22, &b21),
-			(&a21, &b12),
-			(&a22, &b22),
+	//
+	// Each quadrant's two product terms accumulate into one preallocated
+	// buffer: the first term is written with beta=0, the second is
+	// accumulated on top with beta=1.
+	let mut c11 = Matrix::new(m_half, p_half);
+	let mut c12 = Matrix::new(m_half, p - p_half);
+	let mut c21 = Matrix::new(m - m_half, p_half);
+	let mut c22 = Matrix::new(m - m_half, p - p_half);
+
+	if parallel {
+		// Parallel computation using rayon: each quadrant's pair of
+		// accumulating terms runs on one task, and the four quadrants run
+		// concurrently.
+		[
+			(&a11, &b11, &a12, &b21, &mut c11),
+			(&a11, &b12, &a12, &b22, &mut c12),
+			(&a21, &b11, &a22, &b21, &mut c21),
+			(&a21, &b12, &a22, &b22, &mut c22),
 		]
 		.into_par_iter()
-		.map(|(a_sub, b_sub)| divide_conquer_recursive(a_sub, b_sub, threshold, parallel))
-		.collect();
-
-		let c11 = results[0].add(&results[1]);
-		let c12 = results[2].add(&results[3]);
-		let c21 = results[4].add(&results[5]);
-		let c22 = results[6].add(&results[7]);
-
-		(c11, c12, c21, c22)
+		.for_each(|(x1, y1, x2, y2, cq)| {
+			divide_conquer_recursive(x1, y1, threshold, parallel, cq, 0.0);
+			divide_conquer_recursive(x2, y2, threshold, parallel, cq, 1.0);
+		});
 	} else {
 		// Sequential computation
-		let p1 = divide_conquer_recursive(&a11, &b11, threshold, parallel);
-		let p2 = divide_conquer_recursive(&a12, &b21, threshold, parallel);
-		let c11 = p1.add(&p2);
-
-		let p3 = divide_conquer_recursive(&a11, &b12, threshold, parallel);
-		let p4 = divide_conquer_recursive(&a12, &b22, threshold, parallel);
-		let c12 = p3.add(&p4);
+		divide_conquer_recursive(&a11, &b11, threshold, parallel, &mut c11, 0.0);
+		divide_conquer_recursive(&a12, &b21, threshold, parallel, &mut c11, 1.0);
 
-		let p5 = divide_conquer_recursive(&a21, &b11, threshold, parallel);
-		let p6 = divide_conquer_recursive(&a22, &b21, threshold, parallel);
-		let c21 = p5.add(&p6);
+		divide_conquer_recursive(&a11, &b12, threshold, parallel, &mut c12, 0.0);
+		divide_conquer_recursive(&a12, &b22, threshold, parallel, &mut c12, 1.0);
 
-		let p7 = divide_conquer_recursive(&a21, &b12, threshold, parallel);
-		let p8 = divide_conquer_recursive(&a22, &b22, threshold, parallel);
-		let c22 = p7.add(&p8);
+		divide_conquer_recursive(&a21, &b11, threshold, parallel, &mut c21, 0.0);
+		divide_conquer_recursive(&a22, &b21, threshold, parallel, &mut c21, 1.0);
 
-		(c11, c12, c21, c22)
-	};
+		divide_conquer_recursive(&a21, &b12, threshold, parallel, &mut c22, 0.0);
+		divide_conquer_recursive(&a22, &b22, threshold, parallel, &mut c22, 1.0);
+	}
 
-	// Combine quadrants
-	Matrix::combine_quadrants(&c11, &c12, &c21, &c22)
+	// Combine quadrants into c: overwrite if this call's own output hasn't
+	// been accumulated into yet, otherwise add on top of the existing beta*c.
+	if beta == 0.0 {
+		c.write_quadrant(&c11, 0, 0);
+		c.write_quadrant(&c12, 0, p_half);
+		c.write_quadrant(&c21, m_half, 0);
+		c.write_quadrant(&c22, m_half, p_half);
+	} else {
+		if beta != 1.0 {
+			c.scale(beta);
+		}
+		c.add_quadrant(&c11, 0, 0);
+		c.add_quadrant(&c12, 0, p_half);
+		c.add_quadrant(&c21, m_half, 0);
+		c.add_quadrant(&c22, m_half, p_half);
+	}
 }
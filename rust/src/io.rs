@@ -0,0 +1,190 @@
+use crate::matrix::Matrix;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// Errors that can occur while reading or writing MatrixMarket files.
+#[derive(Debug)]
+pub enum MatrixMarketError {
+	/// Underlying file I/O failed.
+	Io(std::io::Error),
+	/// The file did not start with a recognized `%%MatrixMarket` banner.
+	InvalidBanner(String),
+	/// The banner declared a format/field/symmetry combination we don't support.
+	UnsupportedFormat(String),
+	/// The size line was missing or malformed.
+	InvalidSize(String),
+	/// An entry line was missing or malformed.
+	InvalidEntry(String),
+}
+
+impl fmt::Display for MatrixMarketError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MatrixMarketError::Io(e) => write!(f, "I/O error: {}", e),
+			MatrixMarketError::InvalidBanner(s) => write!(f, "invalid MatrixMarket banner: {}", s),
+			MatrixMarketError::UnsupportedFormat(s) => write!(f, "unsupported MatrixMarket format: {}", s),
+			MatrixMarketError::InvalidSize(s) => write!(f, "invalid MatrixMarket size line: {}", s),
+			MatrixMarketError::InvalidEntry(s) => write!(f, "invalid MatrixMarket entry: {}", s),
+		}
+	}
+}
+
+impl std::error::Error for MatrixMarketError {}
+
+impl From<std::io::Error> for MatrixMarketError {
+	fn from(e: std::io::Error) -> Self {
+		MatrixMarketError::Io(e)
+	}
+}
+
+/// The two storage layouts defined by the MatrixMarket format.
+enum MatrixMarketFormat {
+	Coordinate,
+	Array,
+}
+
+impl Matrix {
+	/// Read a dense `Matrix` from a MatrixMarket file (`.mtx`).
+	/// Supports both the `coordinate` layout (sparse triples, 1-based
+	/// row/col indices, zeros filled in for the dense result) and the
+	/// `array` layout (dense column-major values), restricted to the
+	/// `real general` field/symmetry.
+	pub fn from_matrix_market<P: AsRef<Path>>(path: P) -> Result<Matrix, MatrixMarketError> {
+		let file = File::open(path)?;
+		let reader = BufReader::new(file);
+		let mut lines = reader.lines();
+
+		let banner = lines
+			.next()
+			.ok_or_else(|| MatrixMarketError::InvalidBanner("file is empty".to_string()))??;
+		let format = parse_banner(&banner)?;
+
+		// Skip `%` comment lines until the size line.
+		let mut size_line = None;
+		for line in &mut lines {
+			let line = line?;
+			if line.starts_with('%') {
+				continue;
+			}
+			size_line = Some(line);
+			break;
+		}
+		let size_line = size_line
+			.ok_or_else(|| MatrixMarketError::InvalidSize("missing size line".to_string()))?;
+
+		let mut matrix = match format {
+			MatrixMarketFormat::Coordinate => {
+				let mut tokens = size_line.split_whitespace();
+				let rows = parse_usize(tokens.next(), &size_line)?;
+				let cols = parse_usize(tokens.next(), &size_line)?;
+				let nnz = parse_usize(tokens.next(), &size_line)?;
+
+				let mut matrix = Matrix::new(rows, cols);
+				for line in lines {
+					let line = line?;
+					let line = line.trim();
+					if line.is_empty() || line.starts_with('%') {
+						continue;
+					}
+					let mut tokens = line.split_whitespace();
+					let row = parse_usize(tokens.next(), line)?;
+					let col = parse_usize(tokens.next(), line)?;
+					if row == 0 || row > rows || col == 0 || col > cols {
+						return Err(MatrixMarketError::InvalidEntry(line.to_string()));
+					}
+					let row = row - 1;
+					let col = col - 1;
+					let value: f64 = tokens
+						.next()
+						.ok_or_else(|| MatrixMarketError::InvalidEntry(line.to_string()))?
+						.parse()
+						.map_err(|_| MatrixMarketError::InvalidEntry(line.to_string()))?;
+					matrix[(row, col)] = value;
+				}
+
+				let _ = nnz;
+				matrix
+			}
+			MatrixMarketFormat::Array => {
+				let mut tokens = size_line.split_whitespace();
+				let rows = parse_usize(tokens.next(), &size_line)?;
+				let cols = parse_usize(tokens.next(), &size_line)?;
+
+				let mut matrix = Matrix::new(rows, cols);
+				let mut index = 0;
+				for line in lines {
+					let line = line?;
+					let line = line.trim();
+					if line.is_empty() || line.starts_with('%') {
+						continue;
+					}
+					let value: f64 = line
+						.parse()
+						.map_err(|_| MatrixMarketError::InvalidEntry(line.to_string()))?;
+					if index >= rows * cols {
+						return Err(MatrixMarketError::InvalidEntry(
+							"more entries than rows*cols".to_string(),
+						));
+					}
+					matrix.data[index] = value;
+					index += 1;
+				}
+
+				matrix
+			}
+		};
+
+		// Nothing to normalize for `general` symmetry; kept as a hook for
+		// future `symmetric`/`skew-symmetric` support.
+		let _ = &mut matrix;
+		Ok(matrix)
+	}
+
+	/// Write this `Matrix` to a MatrixMarket file (`.mtx`) in the dense
+	/// `array real general` layout (column-major values, one per line).
+	pub fn to_matrix_market<P: AsRef<Path>>(&self, path: P) -> Result<(), MatrixMarketError> {
+		let mut file = File::create(path)?;
+
+		writeln!(file, "%%MatrixMarket matrix array real general")?;
+		writeln!(file, "{} {}", self.rows, self.cols)?;
+		for value in self.data.iter() {
+			writeln!(file, "{}", value)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Parse the `%%MatrixMarket matrix {coordinate|array} real general` banner.
+fn parse_banner(banner: &str) -> Result<MatrixMarketFormat, MatrixMarketError> {
+	let tokens: Vec<&str> = banner.split_whitespace().collect();
+	if tokens.len() != 5 || tokens[0] != "%%MatrixMarket" || tokens[1] != "matrix" {
+		return Err(MatrixMarketError::InvalidBanner(banner.to_string()));
+	}
+
+	let format = match tokens[2] {
+		"coordinate" => MatrixMarketFormat::Coordinate,
+		"array" => MatrixMarketFormat::Array,
+		other => return Err(MatrixMarketError::UnsupportedFormat(other.to_string())),
+	};
+
+	if tokens[3] != "real" || tokens[4] != "general" {
+		return Err(MatrixMarketError::UnsupportedFormat(format!(
+			"{} {}",
+			tokens[3], tokens[4]
+		)));
+	}
+
+	Ok(format)
+}
+
+/// Parse a whitespace-delimited token as a `usize`, tagging errors with the
+/// originating line for a useful error message.
+fn parse_usize(token: Option<&str>, line: &str) -> Result<usize, MatrixMarketError> {
+	token
+		.ok_or_else(|| MatrixMarketError::InvalidSize(line.to_string()))?
+		.parse()
+		.map_err(|_| MatrixMarketError::InvalidSize(line.to_string()))
+}
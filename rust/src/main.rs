@@ -1,17 +1,62 @@
+mod autotune;
 mod benchmark;
 mod iterative_matmul;
 mod divide_conquer_matmul;
 mod strassen_matmul;
+mod blocked_matmul;
 mod matrix;
+mod sparse_matrix;
+mod io;
 
-use benchmark::{print_results_table, run_benchmarks, save_results_csv};
+use autotune::get_or_calibrate;
+use benchmark::{
+	print_results_table, run_benchmarks, run_benchmarks_on_matrices, run_out_of_core_benchmarks,
+	run_sparse_benchmarks, save_results_csv,
+};
+use matrix::Matrix;
 use std::time::Instant;
 use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
 use std::alloc::System;
+use std::path::Path;
 
 #[global_allocator]
 static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
 
+/// Load every `.mtx` file in `dir` as a dense `Matrix`, paired with its
+/// file stem for labeling. Files that fail to parse are skipped with a
+/// warning rather than aborting the whole run.
+fn load_matrix_market_dir(dir: &Path) -> Vec<(String, Matrix)> {
+	let mut matrices = Vec::new();
+
+	let entries = match std::fs::read_dir(dir) {
+		Ok(entries) => entries,
+		Err(e) => {
+			eprintln!("Error reading directory {}: {}", dir.display(), e);
+			return matrices;
+		}
+	};
+
+	for entry in entries.flatten() {
+		let path = entry.path();
+		if path.extension().and_then(|ext| ext.to_str()) != Some("mtx") {
+			continue;
+		}
+
+		let label = path
+			.file_stem()
+			.and_then(|s| s.to_str())
+			.unwrap_or("matrix")
+			.to_string();
+
+		match Matrix::from_matrix_market(&path) {
+			Ok(matrix) => matrices.push((label, matrix)),
+			Err(e) => eprintln!("Skipping {}: {}", path.display(), e),
+		}
+	}
+
+	matrices
+}
+
 /// Main function to run matrix multiplication benchmarks
 fn main() {
 	println!("{}", "=".repeat(80));
@@ -23,18 +68,54 @@ fn main() {
 	println!("Number of threads: {}", num_threads);
 	println!();
 
-	// Define matrix sizes to test
-	// Start small and scale up
-	let sizes = vec![
-		64, 128, 256, 512, 1024, 2048,
-	];
+	// If a directory of MatrixMarket files is given as the first argument,
+	// benchmark against those instead of randomly generated matrices.
+	let mtx_dir = std::env::args().nth(1);
 
-	println!("Testing sizes: {:?}", sizes);
-	println!();
+	// Calibrate (or load a cached calibration of) the recursion threshold
+	// and block sizes before running any benchmarks, so the reported
+	// numbers reflect tuned rather than arbitrary configurations.
+	let params = get_or_calibrate(num_threads);
 
 	// Run benchmarks
 	let start_time = Instant::now();
-	let results = run_benchmarks(&sizes);
+	let results = if let Some(dir) = mtx_dir {
+		let dir = Path::new(&dir);
+		println!("Loading MatrixMarket files from: {}", dir.display());
+		println!();
+
+		let matrices = load_matrix_market_dir(dir);
+		run_benchmarks_on_matrices(&matrices, &params)
+	} else {
+		// Define matrix sizes to test
+		// Start small and scale up
+		let sizes = vec![
+			64, 128, 256, 512, 1024, 2048,
+		];
+
+		println!("Testing sizes: {:?}", sizes);
+		println!();
+
+		let mut results = run_benchmarks(&sizes, &params);
+
+		// Compare the sparse CSC kernel against dense multiplication across
+		// a range of nonzero densities to find the crossover point.
+		let sparse_sizes = vec![512, 1024];
+		let densities = vec![0.01, 0.05, 0.10];
+		results.extend(run_sparse_benchmarks(&sparse_sizes, &densities));
+
+		// Benchmark sizes too large to comfortably fit on the heap, backed
+		// by memory-mapped scratch files instead.
+		let out_of_core_dir = Path::new("../results/mmap_scratch");
+		if let Err(e) = std::fs::create_dir_all(out_of_core_dir) {
+			eprintln!("Error creating out-of-core scratch directory: {}", e);
+		} else {
+			let out_of_core_sizes = vec![4096, 8192];
+			results.extend(run_out_of_core_benchmarks(&out_of_core_sizes, out_of_core_dir, &params));
+		}
+
+		results
+	};
 	let elapsed_time = start_time.elapsed();
 
 	// Display results
@@ -1,10 +1,95 @@
-use std::ops::{Index, IndexMut};
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::path::Path;
+use memmap2::{MmapMut, MmapOptions};
 use rand::Rng;
+use rayon::prelude::*;
+
+/// Below this many elements, elementwise operations (`add`, `sub`, `scale`,
+/// `axpy`) run sequentially rather than paying rayon's parallelization
+/// overhead.
+const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Backing storage for `Matrix::data`: either an owned heap buffer or a
+/// memory-mapped file region. Both deref to the same `[f64]` view, so the
+/// rest of `Matrix` doesn't need to care which one it has.
+pub enum MatrixStorage {
+	Owned(Vec<f64>),
+	Mapped(MmapMut),
+}
+
+impl Deref for MatrixStorage {
+	type Target = [f64];
+
+	fn deref(&self) -> &[f64] {
+		match self {
+			MatrixStorage::Owned(v) => v,
+			MatrixStorage::Mapped(m) => {
+				let len = m.len() / std::mem::size_of::<f64>();
+				// SAFETY: `Matrix::mmap` sizes the mapping to exactly
+				// `rows * cols` `f64`s and never exposes the raw bytes
+				// any other way, so reinterpreting them as `[f64]` is
+				// sound as long as the mapping outlives the slice, which
+				// it does here (borrowed from `&self`).
+				unsafe { std::slice::from_raw_parts(m.as_ptr() as *const f64, len) }
+			}
+		}
+	}
+}
+
+impl DerefMut for MatrixStorage {
+	fn deref_mut(&mut self) -> &mut [f64] {
+		match self {
+			MatrixStorage::Owned(v) => v,
+			MatrixStorage::Mapped(m) => {
+				let len = m.len() / std::mem::size_of::<f64>();
+				// SAFETY: see `Deref::deref` above.
+				unsafe { std::slice::from_raw_parts_mut(m.as_mut_ptr() as *mut f64, len) }
+			}
+		}
+	}
+}
+
+impl Index<usize> for MatrixStorage {
+	type Output = f64;
+
+	#[inline]
+	fn index(&self, idx: usize) -> &f64 {
+		&(**self)[idx]
+	}
+}
+
+impl IndexMut<usize> for MatrixStorage {
+	#[inline]
+	fn index_mut(&mut self, idx: usize) -> &mut f64 {
+		&mut (**self)[idx]
+	}
+}
+
+impl Clone for MatrixStorage {
+	/// Cloning a memory-mapped backing materializes an owned copy — there's
+	/// no cheap way to duplicate a live mapping, and callers that clone a
+	/// `Matrix` want an independent one anyway.
+	fn clone(&self) -> Self {
+		MatrixStorage::Owned(self.to_vec())
+	}
+}
+
+impl fmt::Debug for MatrixStorage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MatrixStorage::Owned(v) => write!(f, "Owned(len={})", v.len()),
+			MatrixStorage::Mapped(m) => write!(f, "Mapped(len={})", m.len() / std::mem::size_of::<f64>()),
+		}
+	}
+}
 
 /// Matrix stored in contiguous memory (column-major order)
 #[derive(Clone, Debug)]
 pub struct Matrix {
-	pub data: Vec<f64>,
+	pub data: MatrixStorage,
 	pub rows: usize,
 	pub cols: usize,
 }
@@ -13,7 +98,7 @@ impl Matrix {
 	/// Create a new matrix with given dimensions, initialized to zero
 	pub fn new(rows: usize, cols: usize) -> Self {
 		Matrix {
-			data: vec![0.0; rows * cols],
+			data: MatrixStorage::Owned(vec![0.0; rows * cols]),
 			rows,
 			cols,
 		}
@@ -23,7 +108,61 @@ impl Matrix {
 	pub fn random(rows: usize, cols: usize) -> Self {
 		let mut rng = rand::thread_rng();
 		let data: Vec<f64> = (0..rows * cols).map(|_| rng.gen::<f64>()).collect();
-		Matrix { data, rows, cols }
+		Matrix { data: MatrixStorage::Owned(data), rows, cols }
+	}
+
+	/// Create a random matrix with the given fraction of entries nonzero
+	/// (values in [0, 1) where present, zero elsewhere). `density` is
+	/// clamped to `[0.0, 1.0]`.
+	pub fn random_sparse(rows: usize, cols: usize, density: f64) -> Self {
+		let density = density.clamp(0.0, 1.0);
+		let mut rng = rand::thread_rng();
+		let data: Vec<f64> = (0..rows * cols)
+			.map(|_| {
+				if rng.gen::<f64>() < density {
+					rng.gen::<f64>()
+				} else {
+					0.0
+				}
+			})
+			.collect();
+		Matrix { data: MatrixStorage::Owned(data), rows, cols }
+	}
+
+	/// Create a matrix backed by a memory-mapped file instead of a heap
+	/// allocation, so it can be larger than available RAM. The file at
+	/// `path` is created (or truncated) and grown to `rows * cols` `f64`s,
+	/// initialized to zero.
+	pub fn mmap<P: AsRef<Path>>(rows: usize, cols: usize, path: P) -> io::Result<Self> {
+		let byte_len = (rows * cols * std::mem::size_of::<f64>()) as u64;
+
+		let file = OpenOptions::new()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(path)?;
+		file.set_len(byte_len)?;
+
+		// SAFETY: the file is exclusively ours (just created/truncated
+		// above) and stays open for the lifetime of the mapping.
+		let mmap = unsafe { MmapOptions::new().map_mut(&file)? };
+
+		Ok(Matrix {
+			data: MatrixStorage::Mapped(mmap),
+			rows,
+			cols,
+		})
+	}
+
+	/// Fill every entry with a random value in [0, 1), in place. Works for
+	/// any backing (heap or memory-mapped), unlike `random`/`random_sparse`
+	/// which always allocate a fresh owned buffer.
+	pub fn fill_random(&mut self) {
+		let mut rng = rand::thread_rng();
+		for v in self.data.iter_mut() {
+			*v = rng.gen::<f64>();
+		}
 	}
 
 	/// Extract a submatrix (creates a copy)
@@ -47,24 +186,94 @@ impl Matrix {
 		result
 	}
 
-	/// Add another matrix in-place (modifies self)
+	/// Borrow a zero-copy view over a contiguous range of full columns
+	/// (`[0, self.rows) x [col_start, col_end)`), which are contiguous in
+	/// column-major storage. Returns `None` for row ranges that don't span
+	/// every row, where no contiguous slice exists and `submatrix` (which
+	/// copies) must be used instead.
+	pub fn submatrix_view(&self, row_start: usize, row_end: usize, col_start: usize, col_end: usize) -> Option<MatrixSlice<'_>> {
+		if row_start != 0 || row_end != self.rows {
+			return None;
+		}
+
+		let start = col_start * self.rows;
+		let end = col_end * self.rows;
+		let data: &[f64] = &self.data;
+		Some(MatrixSlice {
+			data: &data[start..end],
+			rows: self.rows,
+			cols: col_end - col_start,
+		})
+	}
+
+	/// Add another matrix in-place (modifies self). Runs in parallel via
+	/// rayon above `PARALLEL_THRESHOLD` elements, sequentially below it.
 	pub fn add(&mut self, other: &Matrix) -> &mut Self {
 		assert_eq!(self.rows, other.rows);
 		assert_eq!(self.cols, other.cols);
 
-		for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-			*a += b;
+		if self.data.len() >= PARALLEL_THRESHOLD {
+			self.data
+				.par_iter_mut()
+				.zip(other.data.par_iter())
+				.for_each(|(a, b)| *a += b);
+		} else {
+			for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+				*a += b;
+			}
 		}
 		self
 	}
 
-	/// Subtract another matrix in-place (modifies self)
+	/// Subtract another matrix in-place (modifies self). Runs in parallel
+	/// via rayon above `PARALLEL_THRESHOLD` elements, sequentially below it.
 	pub fn sub(&mut self, other: &Matrix) -> &mut Self {
 		assert_eq!(self.rows, other.rows);
 		assert_eq!(self.cols, other.cols);
 
-		for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-			*a -= b;
+		if self.data.len() >= PARALLEL_THRESHOLD {
+			self.data
+				.par_iter_mut()
+				.zip(other.data.par_iter())
+				.for_each(|(a, b)| *a -= b);
+		} else {
+			for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+				*a -= b;
+			}
+		}
+		self
+	}
+
+	/// Scale this matrix in-place by `alpha` (`self *= alpha`). Runs in
+	/// parallel via rayon above `PARALLEL_THRESHOLD` elements, sequentially
+	/// below it.
+	pub fn scale(&mut self, alpha: f64) -> &mut Self {
+		if self.data.len() >= PARALLEL_THRESHOLD {
+			self.data.par_iter_mut().for_each(|a| *a *= alpha);
+		} else {
+			for a in self.data.iter_mut() {
+				*a *= alpha;
+			}
+		}
+		self
+	}
+
+	/// In-place scaled accumulation: `self += alpha * other`. Runs in
+	/// parallel via rayon above `PARALLEL_THRESHOLD` elements, sequentially
+	/// below it.
+	pub fn axpy(&mut self, alpha: f64, other: &Matrix) -> &mut Self {
+		assert_eq!(self.rows, other.rows);
+		assert_eq!(self.cols, other.cols);
+
+		if self.data.len() >= PARALLEL_THRESHOLD {
+			self.data
+				.par_iter_mut()
+				.zip(other.data.par_iter())
+				.for_each(|(a, b)| *a += alpha * b);
+		} else {
+			for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+				*a += alpha * b;
+			}
 		}
 		self
 	}
@@ -78,35 +287,92 @@ impl Matrix {
 
 		let mut result = Matrix::new(rows_top + rows_bottom, cols_left + cols_right);
 
-		// Copy C11
-		for i in 0..c11.rows {
-			for j in 0..c11.cols {
-				result[(i, j)] = c11[(i, j)];
+		result.write_quadrant(c11, 0, 0);
+		result.write_quadrant(c12, 0, cols_left);
+		result.write_quadrant(c21, rows_top, 0);
+		result.write_quadrant(c22, rows_top, cols_left);
+
+		result
+	}
+
+	/// Write `src` into this matrix at the given quadrant offset,
+	/// overwriting the target region in place. Runs in parallel via rayon,
+	/// one task per destination column, above `PARALLEL_THRESHOLD` elements.
+	pub fn write_quadrant(&mut self, src: &Matrix, row_offset: usize, col_offset: usize) {
+		let self_rows = self.rows;
+		if src.data.len() >= PARALLEL_THRESHOLD {
+			self.data
+				.par_chunks_mut(self_rows)
+				.skip(col_offset)
+				.take(src.cols)
+				.enumerate()
+				.for_each(|(j, column)| {
+					for i in 0..src.rows {
+						column[row_offset + i] = src[(i, j)];
+					}
+				});
+		} else {
+			for j in 0..src.cols {
+				for i in 0..src.rows {
+					self[(row_offset + i, col_offset + j)] = src[(i, j)];
+				}
 			}
 		}
+	}
 
-		// Copy C12
-		for i in 0..c12.rows {
-			for j in 0..c12.cols {
-				result[(i, cols_left + j)] = c12[(i, j)];
+	/// Accumulate `src` into this matrix at the given quadrant offset
+	/// (`self[quadrant] += src`), the accumulating counterpart to
+	/// `write_quadrant`. Runs in parallel via rayon, one task per
+	/// destination column, above `PARALLEL_THRESHOLD` elements.
+	pub fn add_quadrant(&mut self, src: &Matrix, row_offset: usize, col_offset: usize) {
+		let self_rows = self.rows;
+		if src.data.len() >= PARALLEL_THRESHOLD {
+			self.data
+				.par_chunks_mut(self_rows)
+				.skip(col_offset)
+				.take(src.cols)
+				.enumerate()
+				.for_each(|(j, column)| {
+					for i in 0..src.rows {
+						column[row_offset + i] += src[(i, j)];
+					}
+				});
+		} else {
+			for j in 0..src.cols {
+				for i in 0..src.rows {
+					self[(row_offset + i, col_offset + j)] += src[(i, j)];
+				}
 			}
 		}
+	}
+
+	/// Generalized matrix-multiply-accumulate: `c = beta*c + alpha*(a*b)`,
+	/// writing directly into the existing `c` buffer. When `beta == 0.0`,
+	/// `c` is overwritten without being read first.
+	///
+	/// # Panics
+	/// Panics if the dimensions of `a`, `b` and `c` are not compatible.
+	pub fn gemm(a: &Matrix, b: &Matrix, c: &mut Matrix, alpha: f64, beta: f64) {
+		assert_eq!(a.cols, b.rows);
+		assert_eq!(c.rows, a.rows);
+		assert_eq!(c.cols, b.cols);
 
-		// Copy C21
-		for i in 0..c21.rows {
-			for j in 0..c21.cols {
-				result[(rows_top + i, j)] = c21[(i, j)];
+		if beta == 0.0 {
+			for v in c.data.iter_mut() {
+				*v = 0.0;
 			}
+		} else if beta != 1.0 {
+			c.scale(beta);
 		}
 
-		// Copy C22
-		for i in 0..c22.rows {
-			for j in 0..c22.cols {
-				result[(rows_top + i, cols_left + j)] = c22[(i, j)];
+		for j in 0..b.cols {
+			for k in 0..a.cols {
+				let scaled_b = alpha * b[(k, j)];
+				for i in 0..a.rows {
+					c[(i, j)] += a[(i, k)] * scaled_b;
+				}
 			}
 		}
-
-		result
 	}
 }
 
@@ -127,3 +393,31 @@ impl IndexMut<(usize, usize)> for Matrix {
 		&mut self.data[col * self.rows + row]
 	}
 }
+
+/// A borrowed, read-only, zero-copy view over a contiguous range of a
+/// `Matrix`'s columns. Returned by `Matrix::submatrix_view`.
+pub struct MatrixSlice<'a> {
+	data: &'a [f64],
+	rows: usize,
+	cols: usize,
+}
+
+impl<'a> MatrixSlice<'a> {
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+}
+
+// Implement indexing: slice[(row, col)], mirroring `Matrix`'s Index impl.
+impl<'a> Index<(usize, usize)> for MatrixSlice<'a> {
+	type Output = f64;
+
+	#[inline]
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		&self.data[col * self.rows + row]
+	}
+}
@@ -0,0 +1,108 @@
+use crate::matrix::Matrix;
+
+/// Magnitude below which an entry is treated as zero when converting a
+/// dense `Matrix` to `SparseMatrix`.
+const SPARSE_TOLERANCE: f64 = 1e-12;
+
+/// Sparse matrix stored in compressed sparse column (CSC) format. CSC fits
+/// naturally alongside `Matrix`'s column-major dense storage: `col_ptr[j]`
+/// is the offset of column `j`'s first entry in `row_idx`/`values`.
+#[derive(Clone, Debug)]
+pub struct SparseMatrix {
+	pub rows: usize,
+	pub cols: usize,
+	pub col_ptr: Vec<usize>,
+	pub row_idx: Vec<usize>,
+	pub values: Vec<f64>,
+}
+
+impl SparseMatrix {
+	/// Number of stored (explicit) nonzero entries.
+	pub fn nnz(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Convert back to a dense `Matrix`, filling unstored entries with zero.
+	pub fn to_dense(&self) -> Matrix {
+		let mut dense = Matrix::new(self.rows, self.cols);
+
+		for col in 0..self.cols {
+			for idx in self.col_ptr[col]..self.col_ptr[col + 1] {
+				let row = self.row_idx[idx];
+				dense[(row, col)] = self.values[idx];
+			}
+		}
+
+		dense
+	}
+}
+
+impl From<&Matrix> for SparseMatrix {
+	/// Build a CSC sparse matrix from a dense one, dropping entries whose
+	/// magnitude is below `SPARSE_TOLERANCE`.
+	fn from(dense: &Matrix) -> Self {
+		let mut col_ptr = Vec::with_capacity(dense.cols + 1);
+		let mut row_idx = Vec::new();
+		let mut values = Vec::new();
+
+		col_ptr.push(0);
+		for col in 0..dense.cols {
+			for row in 0..dense.rows {
+				let value = dense[(row, col)];
+				if value.abs() >= SPARSE_TOLERANCE {
+					row_idx.push(row);
+					values.push(value);
+				}
+			}
+			col_ptr.push(row_idx.len());
+		}
+
+		SparseMatrix {
+			rows: dense.rows,
+			cols: dense.cols,
+			col_ptr,
+			row_idx,
+			values,
+		}
+	}
+}
+
+/// Multiply a sparse CSC matrix by a dense matrix: `a * b`.
+/// Iterates each column of `a` and scatters its nonzeros into the
+/// corresponding row of the (dense) result for every output column.
+///
+/// # Arguments
+/// * `a` - Sparse input matrix (m x n)
+/// * `b` - Dense input matrix (n x p)
+///
+/// # Returns
+/// Dense result matrix (m x p)
+///
+/// # Panics
+/// Panics if matrix dimensions don't match (columns of A != rows of B)
+pub fn sparse_dense_matmul(a: &SparseMatrix, b: &Matrix) -> Matrix {
+	if a.cols != b.rows {
+		panic!(
+			"Matrix dimensions must agree: A is {}x{}, B is {}x{}",
+			a.rows, a.cols, b.rows, b.cols
+		);
+	}
+
+	let mut c = Matrix::new(a.rows, b.cols);
+
+	for j in 0..b.cols {
+		for k in 0..a.cols {
+			let b_val = b[(k, j)];
+			if b_val == 0.0 {
+				continue;
+			}
+
+			for idx in a.col_ptr[k]..a.col_ptr[k + 1] {
+				let row = a.row_idx[idx];
+				c[(row, j)] += a.values[idx] * b_val;
+			}
+		}
+	}
+
+	c
+}
@@ -36,8 +36,13 @@ fn strassen_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel: bool)
 	let n = a.cols;
 	let p = b.cols;
 
-	// Base case: use iterative implementation for fair comparison
-	if m <= threshold || n <= threshold || p <= threshold {
+	// Base case: use iterative implementation for fair comparison. Also
+	// falls back here when a dimension is odd, since Strassen's quadrant
+	// split requires m/n/p to all be even (A11/A22 etc. are only the same
+	// shape when every halving divides evenly) — splitting an odd
+	// dimension would produce mismatched quadrants and panic in `added`/
+	// `subtracted`.
+	if m <= threshold || n <= threshold || p <= threshold || m % 2 != 0 || n % 2 != 0 || p % 2 != 0 {
 		return iterative_matmul(a, b);
 	}
 
@@ -70,13 +75,13 @@ fn strassen_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel: bool)
 	let (m1, m2, m3, m4, m5, m6, m7) = if parallel {
 		// Prepare all inputs for parallel computation
 		let inputs: Vec<(Matrix, Matrix)> = vec![
-			(a11.add(&a22), b11.add(&b22)), // M1
-			(a21.add(&a22), b11.clone()),   // M2
-			(a11.clone(), b12.sub(&b22)),   // M3
-			(a22.clone(), b21.sub(&b11)),   // M4
-			(a11.add(&a12), b22.clone()),   // M5
-			(a21.sub(&a11), b11.add(&b12)), // M6
-			(a12.sub(&a22), b21.add(&b22)), // M7
+			(added(&a11, &a22), added(&b11, &b22)),     // M1
+			(added(&a21, &a22), b11.clone()),           // M2
+			(a11.clone(), subtracted(&b12, &b22)),      // M3
+			(a22.clone(), subtracted(&b21, &b11)),      // M4
+			(added(&a11, &a12), b22.clone()),           // M5
+			(subtracted(&a21, &a11), added(&b11, &b12)), // M6
+			(subtracted(&a12, &a22), added(&b21, &b22)), // M7
 		];
 
 		// Parallel computation using rayon
@@ -96,28 +101,53 @@ fn strassen_recursive(a: &Matrix, b: &Matrix, threshold: usize, parallel: bool)
 		)
 	} else {
 		// Sequential computation
-		let m1 = strassen_recursive(&a11.add(&a22), &b11.add(&b22), threshold, parallel);
-		let m2 = strassen_recursive(&a21.add(&a22), &b11, threshold, parallel);
-		let m3 = strassen_recursive(&a11, &b12.sub(&b22), threshold, parallel);
-		let m4 = strassen_recursive(&a22, &b21.sub(&b11), threshold, parallel);
-		let m5 = strassen_recursive(&a11.add(&a12), &b22, threshold, parallel);
-		let m6 = strassen_recursive(&a21.sub(&a11), &b11.add(&b12), threshold, parallel);
-		let m7 = strassen_recursive(&a12.sub(&a22), &b21.add(&b22), threshold, parallel);
+		let m1 = strassen_recursive(&added(&a11, &a22), &added(&b11, &b22), threshold, parallel);
+		let m2 = strassen_recursive(&added(&a21, &a22), &b11, threshold, parallel);
+		let m3 = strassen_recursive(&a11, &subtracted(&b12, &b22), threshold, parallel);
+		let m4 = strassen_recursive(&a22, &subtracted(&b21, &b11), threshold, parallel);
+		let m5 = strassen_recursive(&added(&a11, &a12), &b22, threshold, parallel);
+		let m6 = strassen_recursive(&subtracted(&a21, &a11), &added(&b11, &b12), threshold, parallel);
+		let m7 = strassen_recursive(&subtracted(&a12, &a22), &added(&b21, &b22), threshold, parallel);
 
 		(m1, m2, m3, m4, m5, m6, m7)
 	};
 
-	// Combine the products to get result quadrants
+	// Combine the products to get result quadrants, via `axpy` so each
+	// term's sign is just its coefficient rather than a separate add/sub
+	// call:
 	// C11 = M1 + M4 - M5 + M7
 	// C12 = M3 + M5
 	// C21 = M2 + M4
 	// C22 = M1 - M2 + M3 + M6
 
-	let c11 = m1.add(&m4).sub(&m5).add(&m7);
-	let c12 = m3.add(&m5);
-	let c21 = m2.add(&m4);
-	let c22 = m1.sub(&m2).add(&m3).add(&m6);
+	let mut c11 = m1.clone();
+	c11.axpy(1.0, &m4).axpy(-1.0, &m5).axpy(1.0, &m7);
+
+	let mut c12 = m3.clone();
+	c12.axpy(1.0, &m5);
+
+	let mut c21 = m2.clone();
+	c21.axpy(1.0, &m4);
+
+	let mut c22 = m1.clone();
+	c22.axpy(-1.0, &m2).axpy(1.0, &m3).axpy(1.0, &m6);
 
 	// Combine quadrants
 	Matrix::combine_quadrants(&c11, &c12, &c21, &c22)
 }
+
+/// Return `a + b` as a new owned matrix. `Matrix::add` mutates in place and
+/// returns `&mut Self`, so combining two borrowed quadrants without
+/// disturbing them needs a scratch clone first.
+fn added(a: &Matrix, b: &Matrix) -> Matrix {
+	let mut result = a.clone();
+	result.add(b);
+	result
+}
+
+/// Return `a - b` as a new owned matrix, the subtracting counterpart to `added`.
+fn subtracted(a: &Matrix, b: &Matrix) -> Matrix {
+	let mut result = a.clone();
+	result.sub(b);
+	result
+}
@@ -1,11 +1,14 @@
 mod iterative_matmul;
 mod divide_conquer_matmul;
 mod strassen_matmul;
+mod blocked_matmul;
 mod matrix;
+mod io;
 
 use iterative_matmul::iterative_matmul;
 use divide_conquer_matmul::divide_conquer_matmul;
 use strassen_matmul::strassen_matmul;
+use blocked_matmul::{blocked_matmul, BLOCK_I, BLOCK_J, BLOCK_K};
 use matrix::Matrix;
 
 /// Calculate Frobenius norm of difference with another matrix
@@ -44,6 +47,52 @@ fn reference_matmul(a: &Matrix, b: &Matrix) -> Matrix {
 	c
 }
 
+/// Exercise `Matrix::from_matrix_market`/`to_matrix_market`: a dense
+/// round-trip, and rejection of a malformed coordinate entry and an
+/// unsupported banner, since this module parses untrusted external files.
+fn test_matrix_market_io() {
+	println!("\nTesting MatrixMarket round-trip and malformed-input rejection");
+
+	let dir = std::env::temp_dir();
+
+	// Round-trip: write a random matrix out and read it back.
+	let original = Matrix::random(5, 3);
+	let path = dir.join("matmul_bench_test_roundtrip.mtx");
+	original
+		.to_matrix_market(&path)
+		.expect("failed to write MatrixMarket file");
+	let loaded = Matrix::from_matrix_market(&path).expect("failed to read MatrixMarket file");
+	let error = matrix_error(&original, &loaded);
+	assert!(error < 1e-10, "MatrixMarket round-trip error too large");
+	println!("  ✓ Array round-trip: error = {}", error);
+	let _ = std::fs::remove_file(&path);
+
+	// A coordinate-format entry with an out-of-range row index must be
+	// rejected with an error rather than underflowing or panicking.
+	let bad_entry_path = dir.join("matmul_bench_test_bad_entry.mtx");
+	std::fs::write(
+		&bad_entry_path,
+		"%%MatrixMarket matrix coordinate real general\n2 2 1\n0 1 2.5\n",
+	)
+	.expect("failed to write malformed MatrixMarket file");
+	let result = Matrix::from_matrix_market(&bad_entry_path);
+	assert!(result.is_err(), "out-of-range coordinate entry should be rejected");
+	println!("  ✓ Out-of-range coordinate entry rejected: {}", result.unwrap_err());
+	let _ = std::fs::remove_file(&bad_entry_path);
+
+	// An unsupported banner (e.g. a complex field) must be rejected too.
+	let bad_banner_path = dir.join("matmul_bench_test_bad_banner.mtx");
+	std::fs::write(
+		&bad_banner_path,
+		"%%MatrixMarket matrix coordinate complex general\n2 2 1\n1 1 2.5\n",
+	)
+	.expect("failed to write malformed MatrixMarket file");
+	let result = Matrix::from_matrix_market(&bad_banner_path);
+	assert!(result.is_err(), "unsupported banner should be rejected");
+	println!("  ✓ Unsupported banner rejected: {}", result.unwrap_err());
+	let _ = std::fs::remove_file(&bad_banner_path);
+}
+
 fn main() {
 	println!("Running correctness tests...");
 	println!("{}", "=".repeat(50));
@@ -87,8 +136,40 @@ fn main() {
 			"Strassen multiplication error too large"
 		);
 		println!("  ✓ Strassen: error = {}", error_strassen);
+
+		// Test blocked (tiled) multiplication
+		let c_blocked = blocked_matmul(&a, &b, BLOCK_I, BLOCK_J, BLOCK_K);
+		let error_blocked = matrix_error(&c_reference, &c_blocked);
+		assert!(
+			error_blocked < 1e-10,
+			"Blocked multiplication error too large"
+		);
+		println!("  ✓ Blocked: error = {}", error_blocked);
+	}
+
+	// Strassen's quadrant split requires even dimensions at every halving;
+	// odd/non-power-of-two sizes exercise the odd-dimension fallback to the
+	// iterative base case instead of panicking on a mismatched quadrant.
+	println!("\nTesting odd sizes against Strassen: 65x65x65, 65x97x65, 65x97x33");
+	for (m, n, p) in [(65, 65, 65), (65, 97, 65), (65, 97, 33)] {
+		let a = Matrix::random(m, n);
+		let b = Matrix::random(n, p);
+
+		let c_reference = reference_matmul(&a, &b);
+		let c_strassen = strassen_matmul(&a, &b, 32, false);
+		let error_strassen = matrix_error(&c_reference, &c_strassen);
+		assert!(
+			error_strassen < 1e-10,
+			"Strassen multiplication error too large for odd size {}x{}x{}",
+			m,
+			n,
+			p
+		);
+		println!("  ✓ Strassen {}x{}x{}: error = {}", m, n, p, error_strassen);
 	}
 
+	test_matrix_market_io();
+
 	println!("\n{}", "=".repeat(50));
 	println!("All tests passed! ✓");
 }